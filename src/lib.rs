@@ -1,52 +1,156 @@
-enum OnlineCardType {
+//! Rules types for RAI-NET Access Battlers.
+//!
+//! The TUI (`ui`) and networking (`net`) dependencies are behind default
+//! features. Other frontends that only need the rules types should depend on
+//! this crate with `default-features = false`.
+
+/// Kind of an online card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OnlineCardType {
     Link,
     Virus,
 }
 
-enum TurnResult {
+/// Outcome of a turn as seen by the player who just moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TurnResult {
     Defeat,
     Victory,
     ChangePlayer,
 }
 
-struct Position {
-    row: u8,
-    col: u8,
+/// A square on the 8x8 board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub row: u8,
+    pub col: u8,
 }
 
-struct OnlineCard {
-    card_type: OnlineCardType,
-    revealed: bool,
-    line_boost_attatched: bool,
-    locate: Position,
-    owner: u8,
+/// An online card on the board or in a stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnlineCard {
+    pub card_type: OnlineCardType,
+    pub revealed: bool,
+    pub line_boost_attached: bool,
+    pub locate: Position,
+    pub owner: u8,
 }
 
-struct Player {
-    id: u8,
-    virus_stack: Vec<OnlineCard>,
-    link_stack: Vec<OnlineCard>,
-    virus_check_used: bool,
-    not_found_used: bool,
+/// A player with their captured cards and single-use terminal cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Player {
+    pub id: u8,
+    pub virus_stack: Vec<OnlineCard>,
+    pub link_stack: Vec<OnlineCard>,
+    pub virus_check_used: bool,
+    pub not_found_used: bool,
 }
 
 impl Player {
-    fn check_turn_result(&self) -> TurnResult {
+    /// Checks the win conditions against the cards in the virus stack.
+    pub fn check_turn_result(&self) -> TurnResult {
         let mut virus_count: u8 = 0;
         let mut links_count: u8 = 0;
         for card in &self.virus_stack {
             match card.card_type {
                 OnlineCardType::Virus => virus_count += 1,
                 OnlineCardType::Link => links_count += 1,
-                _ => {}
             }
         }
         if virus_count >= 4 {
-            return TurnResult::Defeat;
+            TurnResult::Defeat
         } else if links_count >= 4 {
-            return TurnResult::Victory;
+            TurnResult::Victory
         } else {
-            return TurnResult::ChangePlayer;
+            TurnResult::ChangePlayer
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(card_type: OnlineCardType) -> OnlineCard {
+        OnlineCard {
+            card_type,
+            revealed: true,
+            line_boost_attached: false,
+            locate: Position { row: 0, col: 0 },
+            owner: 1,
         }
     }
+
+    fn player_with(viruses: usize, links: usize) -> Player {
+        let mut virus_stack = Vec::new();
+        virus_stack.extend((0..viruses).map(|_| card(OnlineCardType::Virus)));
+        virus_stack.extend((0..links).map(|_| card(OnlineCardType::Link)));
+        Player {
+            id: 0,
+            virus_stack,
+            link_stack: Vec::new(),
+            virus_check_used: false,
+            not_found_used: false,
+        }
+    }
+
+    #[test]
+    fn four_viruses_is_defeat() {
+        assert_eq!(player_with(4, 0).check_turn_result(), TurnResult::Defeat);
+        assert_eq!(player_with(4, 3).check_turn_result(), TurnResult::Defeat);
+    }
+
+    #[test]
+    fn four_links_is_victory() {
+        assert_eq!(player_with(0, 4).check_turn_result(), TurnResult::Victory);
+        assert_eq!(player_with(3, 4).check_turn_result(), TurnResult::Victory);
+    }
+
+    #[test]
+    fn below_threshold_changes_player() {
+        assert_eq!(
+            player_with(0, 0).check_turn_result(),
+            TurnResult::ChangePlayer
+        );
+        assert_eq!(
+            player_with(3, 3).check_turn_result(),
+            TurnResult::ChangePlayer
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn player_json_round_trip() {
+        let player = Player {
+            id: 1,
+            virus_stack: vec![OnlineCard {
+                card_type: OnlineCardType::Virus,
+                revealed: true,
+                line_boost_attached: false,
+                locate: Position { row: 2, col: 3 },
+                owner: 0,
+            }],
+            link_stack: vec![OnlineCard {
+                card_type: OnlineCardType::Link,
+                revealed: false,
+                line_boost_attached: true,
+                locate: Position { row: 7, col: 4 },
+                owner: 0,
+            }],
+            virus_check_used: true,
+            not_found_used: false,
+        };
+        let json = serde_json::to_string(&player).unwrap();
+        let back: Player = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, player);
+    }
 }